@@ -1,7 +1,36 @@
+mod persistence;
+
 use crate::RespFrame;
 use dashmap::{DashMap, DashSet};
+use std::collections::HashSet;
 use std::ops::Deref;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Bound on each subscriber's outgoing message queue.
+pub const PUBSUB_CHANNEL_CAPACITY: usize = 128;
+
+/// Default snapshot file, written by `SAVE`/`BGSAVE` and loaded on startup.
+pub const DEFAULT_RDB_PATH: &str = "dump.rdb";
+
+/// How many keys the active-expiration cycle samples from `expires` per pass.
+const EXPIRE_SAMPLE_SIZE: usize = 20;
+/// Keep sampling-and-evicting while more than this fraction of the sample
+/// was expired, mirroring Redis's own active-expiration heuristic.
+const EXPIRE_SAMPLE_THRESHOLD: f64 = 0.25;
+
+/// Outcome of a `TTL`/`PTTL` lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyTtl {
+    /// The key doesn't exist (Redis reports this as `-2`).
+    Missing,
+    /// The key exists but has no expiry set (reported as `-1`).
+    Persistent,
+    /// The key exists and expires after the given duration.
+    Remaining(Duration),
+}
 
 #[derive(Debug, Clone)]
 pub struct Backend(Arc<BackendInner>);
@@ -11,6 +40,9 @@ pub struct BackendInner {
     pub(crate) map: DashMap<String, RespFrame>,
     pub(crate) hmap: DashMap<String, DashMap<String, RespFrame>>,
     pub(crate) set_map: DashMap<String, DashSet<String>>,
+    pub(crate) expires: DashMap<String, Instant>,
+    pub(crate) channels: DashMap<String, Vec<mpsc::Sender<RespFrame>>>,
+    pub(crate) path: PathBuf,
 }
 
 impl Deref for Backend {
@@ -33,16 +65,47 @@ impl Default for BackendInner {
             map: DashMap::new(),
             hmap: DashMap::new(),
             set_map: DashMap::new(),
+            expires: DashMap::new(),
+            channels: DashMap::new(),
+            path: PathBuf::from(DEFAULT_RDB_PATH),
         }
     }
 }
 
 impl Backend {
+    /// Load the default snapshot (`dump.rdb`) if present, otherwise start
+    /// with an empty, in-memory backend.
     pub fn new() -> Self {
-        Self::default()
+        Self::load_from(DEFAULT_RDB_PATH)
+    }
+
+    /// Load a snapshot from `path`, rooting the backend at `path`. A missing
+    /// file, or any other error opening or reading it, yields an empty
+    /// backend; a truncated file keeps the entries read before the cutoff
+    /// rather than starting empty.
+    pub fn load_from(path: impl AsRef<Path>) -> Self {
+        match persistence::load(path.as_ref()) {
+            Ok(inner) => Self(Arc::new(inner)),
+            Err(_) => Self(Arc::new(BackendInner {
+                path: path.as_ref().to_path_buf(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// Serialize the current contents to `path`.
+    pub fn save_to(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        persistence::save(&self.0, path.as_ref())
+    }
+
+    /// Serialize the current contents to the backend's configured path
+    /// (used by the `SAVE` command).
+    pub fn save(&self) -> std::io::Result<()> {
+        self.save_to(&self.0.path)
     }
 
     pub fn get(&self, key: &str) -> Option<RespFrame> {
+        self.expire_if_needed(key);
         self.map.get(key).map(|v| v.value().clone())
     }
 
@@ -51,6 +114,7 @@ impl Backend {
     }
 
     pub fn hget(&self, key: &str, field: &str) -> Option<RespFrame> {
+        self.expire_if_needed(key);
         self.hmap
             .get(key)
             .and_then(|v| v.get(field).map(|v| v.value().clone()))
@@ -77,6 +141,7 @@ impl Backend {
     }
 
     pub fn smembers(&self, key: &str) -> Option<DashSet<String>> {
+        self.expire_if_needed(key);
         self.set_map.get(key).map(|v| v.clone())
     }
 
@@ -89,6 +154,7 @@ impl Backend {
     }
 
     pub fn sismember(&self, key: &str, value: String) -> i64 {
+        self.expire_if_needed(key);
         let ismember = self
             .set_map
             .get(key)
@@ -98,4 +164,296 @@ impl Backend {
             false => 0,
         }
     }
+
+    pub fn srem(&self, key: &str, values: &[String]) -> i64 {
+        self.expire_if_needed(key);
+        match self.set_map.get(key) {
+            Some(set) => values.iter().filter(|value| set.remove(*value).is_some()).count() as i64,
+            None => 0,
+        }
+    }
+
+    pub fn scard(&self, key: &str) -> i64 {
+        self.expire_if_needed(key);
+        self.set_map.get(key).map_or(0, |set| set.len() as i64)
+    }
+
+    /// Remove and return up to `count` members of `key`'s set. Redis picks
+    /// these at random; this takes them in `DashSet`'s iteration order
+    /// instead, which avoids pulling in a `rand` dependency for it but means
+    /// the same members are popped first on a given set every time.
+    pub fn spop(&self, key: &str, count: usize) -> Vec<String> {
+        self.expire_if_needed(key);
+        let Some(set) = self.set_map.get(key) else {
+            return Vec::new();
+        };
+        let popped: Vec<String> = set.iter().take(count).map(|m| m.key().clone()).collect();
+        for member in &popped {
+            set.remove(member);
+        }
+        popped
+    }
+
+    /// Snapshot the members of `key`'s set, treating a missing key as the
+    /// empty set.
+    fn snapshot_set(&self, key: &str) -> HashSet<String> {
+        self.expire_if_needed(key);
+        self.set_map
+            .get(key)
+            .map(|set| set.iter().map(|m| m.key().clone()).collect())
+            .unwrap_or_default()
+    }
+
+    pub fn sinter(&self, keys: &[String]) -> HashSet<String> {
+        let mut iter = keys.iter();
+        let Some(first) = iter.next() else {
+            return HashSet::new();
+        };
+        let mut result = self.snapshot_set(first);
+        for key in iter {
+            let other = self.snapshot_set(key);
+            result.retain(|member| other.contains(member));
+        }
+        result
+    }
+
+    pub fn sunion(&self, keys: &[String]) -> HashSet<String> {
+        let mut result = HashSet::new();
+        for key in keys {
+            result.extend(self.snapshot_set(key));
+        }
+        result
+    }
+
+    pub fn sdiff(&self, keys: &[String]) -> HashSet<String> {
+        let mut iter = keys.iter();
+        let Some(first) = iter.next() else {
+            return HashSet::new();
+        };
+        let mut result = self.snapshot_set(first);
+        for key in iter {
+            let other = self.snapshot_set(key);
+            result.retain(|member| !other.contains(member));
+        }
+        result
+    }
+
+    /// Set `key`'s expiration deadline to `now + duration`. Returns 1 if the
+    /// key exists, 0 otherwise (matching `EXPIRE`/`PEXPIRE`).
+    pub fn expire(&self, key: &str, duration: Duration) -> i64 {
+        self.expire_if_needed(key);
+        if !self.key_exists(key) {
+            return 0;
+        }
+        self.expires.insert(key.to_string(), Instant::now() + duration);
+        1
+    }
+
+    /// Remaining time-to-live for `key`.
+    pub fn ttl(&self, key: &str) -> KeyTtl {
+        self.expire_if_needed(key);
+        if !self.key_exists(key) {
+            return KeyTtl::Missing;
+        }
+        match self.expires.get(key) {
+            Some(deadline) => KeyTtl::Remaining(deadline.saturating_duration_since(Instant::now())),
+            None => KeyTtl::Persistent,
+        }
+    }
+
+    /// Remove `key`'s expiration deadline. Returns 1 if one existed.
+    pub fn persist(&self, key: &str) -> i64 {
+        self.expire_if_needed(key);
+        match self.expires.remove(key) {
+            Some(_) => 1,
+            None => 0,
+        }
+    }
+
+    fn key_exists(&self, key: &str) -> bool {
+        self.map.contains_key(key) || self.hmap.contains_key(key) || self.set_map.contains_key(key)
+    }
+
+    /// If `key` has a deadline that has passed, delete it from every map.
+    /// Returns whether the key was evicted.
+    fn expire_if_needed(&self, key: &str) -> bool {
+        let expired = self
+            .expires
+            .get(key)
+            .is_some_and(|deadline| Instant::now() >= *deadline);
+
+        if expired {
+            self.map.remove(key);
+            self.hmap.remove(key);
+            self.set_map.remove(key);
+            self.expires.remove(key);
+        }
+
+        expired
+    }
+
+    /// Spawn a background task that periodically runs
+    /// `active_expire_cycle`, evicting expired keys that are never read
+    /// again as far as that cycle's sampling reaches them.
+    pub fn spawn_active_expire_cycle(&self, interval: Duration) {
+        let backend = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                backend.active_expire_cycle();
+            }
+        });
+    }
+
+    /// Redis-style active expiration: sample a bounded number of keys with a
+    /// deadline, evict the expired ones, and keep sampling while more than
+    /// `EXPIRE_SAMPLE_THRESHOLD` of the sample was expired.
+    ///
+    /// The sample is the first `EXPIRE_SAMPLE_SIZE` entries in `expires`'
+    /// iteration order, not a random pick: when those are mostly unexpired,
+    /// the cycle stops and expired keys further along are not evicted by it.
+    fn active_expire_cycle(&self) {
+        loop {
+            let sample: Vec<String> = self
+                .expires
+                .iter()
+                .take(EXPIRE_SAMPLE_SIZE)
+                .map(|e| e.key().clone())
+                .collect();
+            if sample.is_empty() {
+                return;
+            }
+
+            let evicted = sample.iter().filter(|key| self.expire_if_needed(key)).count();
+            if (evicted as f64) / (sample.len() as f64) <= EXPIRE_SAMPLE_THRESHOLD {
+                return;
+            }
+        }
+    }
+
+    /// Register `sender` as a subscriber of `channel`.
+    pub fn subscribe(&self, channel: String, sender: mpsc::Sender<RespFrame>) {
+        let mut senders = self.channels.entry(channel).or_default();
+        senders.push(sender);
+    }
+
+    /// Detach `sender` from `channel`.
+    pub fn unsubscribe(&self, channel: &str, sender: &mpsc::Sender<RespFrame>) {
+        if let Some(mut senders) = self.channels.get_mut(channel) {
+            senders.retain(|s| !s.same_channel(sender));
+        }
+    }
+
+    /// Push `message` to every live subscriber of `channel`, pruning any
+    /// whose receiver has been dropped. Returns the number of subscribers
+    /// delivered to.
+    pub fn publish(&self, channel: &str, message: RespFrame) -> i64 {
+        let Some(mut senders) = self.channels.get_mut(channel) else {
+            return 0;
+        };
+
+        let frame: RespFrame = crate::RespArray::new(vec![
+            RespFrame::BulkString("message".into()),
+            RespFrame::BulkString(channel.into()),
+            message,
+        ])
+        .into();
+
+        let mut delivered = 0i64;
+        senders.retain(|sender| match sender.try_send(frame.clone()) {
+            Ok(()) => {
+                delivered += 1;
+                true
+            }
+            Err(mpsc::error::TrySendError::Full(_)) => true,
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        });
+
+        delivered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expire_and_ttl() {
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+
+        assert_eq!(backend.ttl("key"), KeyTtl::Persistent);
+        assert_eq!(backend.ttl("missing"), KeyTtl::Missing);
+
+        assert_eq!(backend.expire("key", Duration::from_secs(60)), 1);
+        assert_eq!(backend.expire("missing", Duration::from_secs(60)), 0);
+        assert!(matches!(backend.ttl("key"), KeyTtl::Remaining(_)));
+
+        assert_eq!(backend.persist("key"), 1);
+        assert_eq!(backend.persist("key"), 0);
+        assert_eq!(backend.ttl("key"), KeyTtl::Persistent);
+    }
+
+    #[test]
+    fn test_lazy_expiration_evicts_on_read() {
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+        backend.expire("key", Duration::from_millis(1));
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(backend.get("key"), None);
+        assert_eq!(backend.ttl("key"), KeyTtl::Missing);
+    }
+
+    #[test]
+    fn test_expire_and_persist_do_not_resurrect_expired_key() {
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+        backend.expire("key", Duration::from_millis(1));
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        // The key has logically expired but hasn't been lazily evicted by a
+        // read yet; neither `expire` nor `persist` should act as if it's
+        // still there.
+        assert_eq!(backend.expire("key", Duration::from_secs(60)), 0);
+        assert_eq!(backend.persist("key"), 0);
+        assert_eq!(backend.get("key"), None);
+    }
+
+    #[test]
+    fn test_set_commands_do_not_see_expired_set() {
+        let backend = Backend::default();
+        backend.sadd("set", &["a".to_string(), "b".to_string()]);
+        backend.expire("set", Duration::from_millis(1));
+
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(backend.sismember("set", "a".to_string()), 0);
+        assert_eq!(backend.scard("set"), 0);
+        assert_eq!(backend.srem("set", &["a".to_string()]), 0);
+        assert_eq!(backend.spop("set", 1), Vec::<String>::new());
+        assert_eq!(backend.sinter(&["set".to_string()]), HashSet::new());
+    }
+
+    #[test]
+    fn test_active_expire_cycle_evicts_expired_keys() {
+        let backend = Backend::default();
+        for i in 0..(EXPIRE_SAMPLE_SIZE * 2) {
+            let key = format!("key{i}");
+            backend.set(key.clone(), RespFrame::BulkString("value".into()));
+            backend.expire(&key, Duration::from_millis(1));
+        }
+
+        std::thread::sleep(Duration::from_millis(20));
+        backend.active_expire_cycle();
+
+        for i in 0..(EXPIRE_SAMPLE_SIZE * 2) {
+            let key = format!("key{i}");
+            assert!(!backend.expires.contains_key(&key));
+            assert!(!backend.map.contains_key(&key));
+        }
+    }
 }
@@ -0,0 +1,345 @@
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use bytes::{Buf, BytesMut};
+use dashmap::{DashMap, DashSet};
+
+use crate::{RespDecode, RespEncode, RespFrame};
+
+use super::BackendInner;
+
+const TAG_STRING: u8 = 0;
+const TAG_HASH: u8 = 1;
+const TAG_SET: u8 = 2;
+const TAG_EXPIRE: u8 = 3;
+
+/// Serialize `inner`'s three maps, plus any live `expires` deadlines, to
+/// `path` as a length-prefixed binary snapshot. Each entry is
+/// `<tag:u8><key_len:u32><key><payload>`, where `payload` depends on the tag:
+/// - string: the value's RESP-encoded bytes (self-framing)
+/// - hash: `<field_count:u32>` then `<field_len:u32><field><value RESP bytes>` per field
+/// - set: `<member_count:u32>` then `<member_len:u32><member>` per member
+/// - expire: `<deadline_epoch_millis:u64>`, the key's absolute wall-clock
+///   deadline, so it survives a process restart between `SAVE` and `load`
+pub(super) fn save(inner: &BackendInner, path: &Path) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+
+    let now = Instant::now();
+    // Keys whose deadline has already passed are dropped from the snapshot
+    // entirely (value and all), the same as the lazy eviction a `get` would
+    // have performed; writing the value out without its expire marker would
+    // silently turn it into a permanent key on load.
+    let expired_keys: HashSet<String> = inner
+        .expires
+        .iter()
+        .filter(|e| now >= *e.value())
+        .map(|e| e.key().clone())
+        .collect();
+
+    for entry in inner.map.iter() {
+        if expired_keys.contains(entry.key()) {
+            continue;
+        }
+        write_tagged_key(&mut out, TAG_STRING, entry.key())?;
+        out.write_all(&entry.value().clone().encode())?;
+    }
+
+    for entry in inner.hmap.iter() {
+        if expired_keys.contains(entry.key()) {
+            continue;
+        }
+        write_tagged_key(&mut out, TAG_HASH, entry.key())?;
+        let hmap = entry.value();
+        out.write_all(&(hmap.len() as u32).to_le_bytes())?;
+        for field in hmap.iter() {
+            write_len_prefixed(&mut out, field.key().as_bytes())?;
+            out.write_all(&field.value().clone().encode())?;
+        }
+    }
+
+    for entry in inner.set_map.iter() {
+        if expired_keys.contains(entry.key()) {
+            continue;
+        }
+        write_tagged_key(&mut out, TAG_SET, entry.key())?;
+        let set = entry.value();
+        out.write_all(&(set.len() as u32).to_le_bytes())?;
+        for member in set.iter() {
+            write_len_prefixed(&mut out, member.key().as_bytes())?;
+        }
+    }
+
+    let wall_now = SystemTime::now();
+    for entry in inner.expires.iter() {
+        if expired_keys.contains(entry.key()) {
+            continue;
+        }
+        let remaining = entry.value().saturating_duration_since(now);
+        let Ok(deadline_epoch) = (wall_now + remaining).duration_since(UNIX_EPOCH) else {
+            continue;
+        };
+        write_tagged_key(&mut out, TAG_EXPIRE, entry.key())?;
+        out.write_all(&(deadline_epoch.as_millis() as u64).to_le_bytes())?;
+    }
+
+    out.flush()
+}
+
+/// Reconstruct a `BackendInner` from a snapshot written by [`save`]. A
+/// missing file yields an empty backend. A truncated file is not rejected
+/// and does not yield an empty backend: the entries parsed before the
+/// cutoff are kept.
+pub(super) fn load(path: &Path) -> io::Result<BackendInner> {
+    let map = DashMap::new();
+    let hmap = DashMap::new();
+    let set_map = DashMap::new();
+    let expires = DashMap::new();
+
+    let mut reader = match File::open(path) {
+        Ok(f) => BufReader::new(f),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(BackendInner {
+                map,
+                hmap,
+                set_map,
+                expires,
+                channels: DashMap::new(),
+                path: path.to_path_buf(),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    let mut buf = BytesMut::from(&bytes[..]);
+
+    // A truncated snapshot just stops the load early; whatever was parsed
+    // before the cutoff is kept.
+    while !buf.is_empty() {
+        let Some((tag, key)) = read_tagged_key(&mut buf) else {
+            break;
+        };
+
+        match tag {
+            TAG_STRING => {
+                let Ok(value) = RespFrame::decode(&mut buf) else {
+                    break;
+                };
+                map.insert(key, value);
+            }
+            TAG_HASH => {
+                let Some(count) = read_u32(&mut buf) else {
+                    break;
+                };
+                let fields = DashMap::new();
+                for _ in 0..count {
+                    let Some(field) = read_len_prefixed(&mut buf) else {
+                        break;
+                    };
+                    let Ok(value) = RespFrame::decode(&mut buf) else {
+                        break;
+                    };
+                    fields.insert(field, value);
+                }
+                hmap.insert(key, fields);
+            }
+            TAG_SET => {
+                let Some(count) = read_u32(&mut buf) else {
+                    break;
+                };
+                let members = DashSet::new();
+                for _ in 0..count {
+                    let Some(member) = read_len_prefixed(&mut buf) else {
+                        break;
+                    };
+                    members.insert(member);
+                }
+                set_map.insert(key, members);
+            }
+            TAG_EXPIRE => {
+                let Some(deadline_epoch_ms) = read_u64(&mut buf) else {
+                    break;
+                };
+                let now_epoch_ms = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or(0);
+
+                if deadline_epoch_ms <= now_epoch_ms {
+                    // The deadline passed while the snapshot sat on disk;
+                    // drop the key entirely rather than reviving it with a
+                    // fresh, incorrect TTL.
+                    map.remove(&key);
+                    hmap.remove(&key);
+                    set_map.remove(&key);
+                } else {
+                    let remaining = Duration::from_millis(deadline_epoch_ms - now_epoch_ms);
+                    expires.insert(key, Instant::now() + remaining);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    Ok(BackendInner {
+        map,
+        hmap,
+        set_map,
+        expires,
+        channels: DashMap::new(),
+        path: path.to_path_buf(),
+    })
+}
+
+fn write_tagged_key(out: &mut impl Write, tag: u8, key: &str) -> io::Result<()> {
+    out.write_all(&[tag])?;
+    write_len_prefixed(out, key.as_bytes())
+}
+
+fn write_len_prefixed(out: &mut impl Write, bytes: &[u8]) -> io::Result<()> {
+    out.write_all(&(bytes.len() as u32).to_le_bytes())?;
+    out.write_all(bytes)
+}
+
+fn read_tagged_key(buf: &mut BytesMut) -> Option<(u8, String)> {
+    if buf.is_empty() {
+        return None;
+    }
+    let tag = buf[0];
+    buf.advance(1);
+    let key = read_len_prefixed(buf)?;
+    Some((tag, key))
+}
+
+fn read_len_prefixed(buf: &mut BytesMut) -> Option<String> {
+    let len = read_u32(buf)? as usize;
+    if buf.len() < len {
+        return None;
+    }
+    let data = buf.split_to(len);
+    String::from_utf8(data.to_vec()).ok()
+}
+
+fn read_u32(buf: &mut BytesMut) -> Option<u32> {
+    if buf.len() < 4 {
+        return None;
+    }
+    let data = buf.split_to(4);
+    Some(u32::from_le_bytes(data[..4].try_into().unwrap()))
+}
+
+fn read_u64(buf: &mut BytesMut) -> Option<u64> {
+    if buf.len() < 8 {
+        return None;
+    }
+    let data = buf.split_to(8);
+    Some(u64::from_le_bytes(data[..8].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Backend;
+    use anyhow::Result;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn tmp_path(name: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("simple-redis-{}-{}.rdb", name, nonce))
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() -> Result<()> {
+        let path = tmp_path("round-trip");
+
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+        backend.hset(
+            "hash".to_string(),
+            "field".to_string(),
+            RespFrame::BulkString("field-value".into()),
+        );
+        backend.sadd("set", &["a".to_string(), "b".to_string()]);
+
+        backend.save_to(&path)?;
+
+        let loaded = Backend::load_from(&path);
+        assert_eq!(
+            loaded.get("key"),
+            Some(RespFrame::BulkString("value".into()))
+        );
+        assert_eq!(
+            loaded.hget("hash", "field"),
+            Some(RespFrame::BulkString("field-value".into()))
+        );
+        let set = loaded.smembers("set").expect("set should round-trip");
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("a"));
+        assert!(set.contains("b"));
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let backend = Backend::load_from(tmp_path("does-not-exist"));
+        assert_eq!(backend.get("anything"), None);
+    }
+
+    #[test]
+    fn test_ttl_survives_save_and_load_round_trip() -> Result<()> {
+        let path = tmp_path("ttl-round-trip");
+
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+        backend.expire("key", Duration::from_secs(60));
+
+        backend.save_to(&path)?;
+
+        let loaded = Backend::load_from(&path);
+        assert_eq!(
+            loaded.get("key"),
+            Some(RespFrame::BulkString("value".into()))
+        );
+        assert!(matches!(loaded.ttl("key"), crate::KeyTtl::Remaining(_)));
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_already_expired_key_is_left_out_of_snapshot() -> Result<()> {
+        let path = tmp_path("expired-dropped");
+
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+        backend.expire("key", Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(20));
+
+        // No read happens before the save, so lazy eviction never runs and
+        // the key is still in `map` with a deadline that has already
+        // passed; `save` must leave it out of the snapshot.
+        backend.expires.insert(
+            "key".to_string(),
+            Instant::now() - Duration::from_millis(1),
+        );
+        save(&backend, &path)?;
+
+        let loaded = Backend::load_from(&path);
+        assert_eq!(loaded.get("key"), None);
+        assert_eq!(loaded.ttl("key"), crate::KeyTtl::Missing);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -45,7 +45,7 @@ mod tests {
 
     #[test]
     fn test_echo_command() -> Result<()> {
-        let backend = Backend::new();
+        let backend = Backend::default();
         let cmd = Echo {
             value: "hello".to_string(),
         };
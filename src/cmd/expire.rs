@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use super::{extract_args, validate_command, CommandError, CommandExecutor, Expire, Pexpire, Persist, Pttl, Ttl};
+use crate::{Backend, KeyTtl, RespArray, RespFrame};
+
+impl CommandExecutor for Expire {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(backend.expire(&self.key, Duration::from_secs(self.seconds)))
+    }
+}
+
+impl CommandExecutor for Pexpire {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(backend.expire(&self.key, Duration::from_millis(self.millis)))
+    }
+}
+
+impl CommandExecutor for Ttl {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(match backend.ttl(&self.key) {
+            KeyTtl::Missing => -2,
+            KeyTtl::Persistent => -1,
+            // Round up rather than truncate, so a key set with `EXPIRE key 60`
+            // reports `60` (not `59`) the instant any time has elapsed.
+            KeyTtl::Remaining(d) => (d.as_millis() as i64 + 999) / 1000,
+        })
+    }
+}
+
+impl CommandExecutor for Pttl {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(match backend.ttl(&self.key) {
+            KeyTtl::Missing => -2,
+            KeyTtl::Persistent => -1,
+            KeyTtl::Remaining(d) => d.as_millis() as i64,
+        })
+    }
+}
+
+impl CommandExecutor for Persist {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(backend.persist(&self.key))
+    }
+}
+
+impl TryFrom<RespArray> for Expire {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["expire"], 2)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match (args.next(), args.next()) {
+            (Some(RespFrame::BulkString(key)), Some(RespFrame::BulkString(seconds))) => {
+                Ok(Expire {
+                    key: String::from_utf8(key.0)?,
+                    seconds: parse_u64(&seconds)?,
+                })
+            }
+            _ => Err(CommandError::InvalidArgument(
+                "Invalid key or seconds".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Pexpire {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["pexpire"], 2)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match (args.next(), args.next()) {
+            (Some(RespFrame::BulkString(key)), Some(RespFrame::BulkString(millis))) => {
+                Ok(Pexpire {
+                    key: String::from_utf8(key.0)?,
+                    millis: parse_u64(&millis)?,
+                })
+            }
+            _ => Err(CommandError::InvalidArgument(
+                "Invalid key or millis".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Ttl {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["ttl"], 1)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match args.next() {
+            Some(RespFrame::BulkString(key)) => Ok(Ttl {
+                key: String::from_utf8(key.0)?,
+            }),
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Pttl {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["pttl"], 1)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match args.next() {
+            Some(RespFrame::BulkString(key)) => Ok(Pttl {
+                key: String::from_utf8(key.0)?,
+            }),
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Persist {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["persist"], 1)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match args.next() {
+            Some(RespFrame::BulkString(key)) => Ok(Persist {
+                key: String::from_utf8(key.0)?,
+            }),
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        }
+    }
+}
+
+fn parse_u64(frame: &crate::BulkString) -> Result<u64, CommandError> {
+    String::from_utf8(frame.to_vec())?
+        .parse()
+        .map_err(|_| CommandError::InvalidArgument("Invalid integer".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Backend, RespDecode};
+    use anyhow::Result;
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_expire_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$6\r\nexpire\r\n$3\r\nkey\r\n$2\r\n60\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: Expire = frame.try_into()?;
+        assert_eq!(result.key, "key");
+        assert_eq!(result.seconds, 60);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ttl_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$3\r\nttl\r\n$3\r\nkey\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: Ttl = frame.try_into()?;
+        assert_eq!(result.key, "key");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expire_ttl_persist_command() -> Result<()> {
+        let backend = Backend::default();
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+
+        let cmd = Expire {
+            key: "key".to_string(),
+            seconds: 60,
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(1));
+
+        let cmd = Ttl {
+            key: "key".to_string(),
+        };
+        // Rounded up, so a little wall-clock drift between `EXPIRE` and `TTL`
+        // doesn't make this flaky; only the rounded bucket is asserted.
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(60));
+
+        let cmd = Persist {
+            key: "key".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(1));
+
+        let cmd = Ttl {
+            key: "key".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(-1));
+
+        let cmd = Ttl {
+            key: "missing".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(-2));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+mod echo;
+mod expire;
+mod persistence;
+mod pubsub;
+mod set;
+
+pub use persistence::Bgsave;
+
+use thiserror::Error;
+
+use crate::{Backend, RespArray, RespError, RespFrame};
+
+#[derive(Error, Debug)]
+pub enum CommandError {
+    #[error("Invalid command: {0}")]
+    InvalidCommand(String),
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+    #[error("{0}")]
+    RespError(#[from] RespError),
+    #[error("Utf8 error: {0}")]
+    Utf8Error(#[from] std::string::FromUtf8Error),
+}
+
+pub trait CommandExecutor {
+    fn execute(self, backend: &Backend) -> RespFrame;
+}
+
+#[derive(Debug)]
+pub struct Echo {
+    pub(crate) value: String,
+}
+
+#[derive(Debug)]
+pub struct SAdd {
+    pub(crate) key: String,
+    pub(crate) values: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SMembers {
+    pub(crate) key: String,
+    pub(crate) sort: bool,
+}
+
+#[derive(Debug)]
+pub struct SIsMember {
+    pub(crate) key: String,
+    pub(crate) value: String,
+}
+
+#[derive(Debug)]
+pub struct SRem {
+    pub(crate) key: String,
+    pub(crate) values: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SCard {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub struct SPop {
+    pub(crate) key: String,
+    /// `None` means the classic single-member `SPOP key` form; `Some(n)` is
+    /// the `SPOP key count` form, which always replies with an array.
+    pub(crate) count: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct SInter {
+    pub(crate) keys: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SUnion {
+    pub(crate) keys: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct SDiff {
+    pub(crate) keys: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Publish {
+    pub(crate) channel: String,
+    pub(crate) message: String,
+}
+
+#[derive(Debug)]
+pub struct Subscribe {
+    pub(crate) channels: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Unsubscribe {
+    pub(crate) channels: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct Save;
+
+#[derive(Debug)]
+pub struct Expire {
+    pub(crate) key: String,
+    pub(crate) seconds: u64,
+}
+
+#[derive(Debug)]
+pub struct Pexpire {
+    pub(crate) key: String,
+    pub(crate) millis: u64,
+}
+
+#[derive(Debug)]
+pub struct Ttl {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub struct Pttl {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub struct Persist {
+    pub(crate) key: String,
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Echo(Echo),
+    SAdd(SAdd),
+    SMembers(SMembers),
+    SIsMember(SIsMember),
+    SRem(SRem),
+    SCard(SCard),
+    SPop(SPop),
+    SInter(SInter),
+    SUnion(SUnion),
+    SDiff(SDiff),
+    Publish(Publish),
+    Subscribe(Subscribe),
+    Unsubscribe(Unsubscribe),
+    Save(Save),
+    Bgsave(Bgsave),
+    Expire(Expire),
+    Pexpire(Pexpire),
+    Ttl(Ttl),
+    Pttl(Pttl),
+    Persist(Persist),
+}
+
+impl CommandExecutor for Command {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        match self {
+            Command::Echo(cmd) => cmd.execute(backend),
+            Command::SAdd(cmd) => cmd.execute(backend),
+            Command::SMembers(cmd) => cmd.execute(backend),
+            Command::SIsMember(cmd) => cmd.execute(backend),
+            Command::SRem(cmd) => cmd.execute(backend),
+            Command::SCard(cmd) => cmd.execute(backend),
+            Command::SPop(cmd) => cmd.execute(backend),
+            Command::SInter(cmd) => cmd.execute(backend),
+            Command::SUnion(cmd) => cmd.execute(backend),
+            Command::SDiff(cmd) => cmd.execute(backend),
+            Command::Publish(cmd) => cmd.execute(backend),
+            Command::Subscribe(cmd) => cmd.execute(backend),
+            Command::Unsubscribe(cmd) => cmd.execute(backend),
+            Command::Save(cmd) => cmd.execute(backend),
+            Command::Bgsave(cmd) => cmd.execute(backend),
+            Command::Expire(cmd) => cmd.execute(backend),
+            Command::Pexpire(cmd) => cmd.execute(backend),
+            Command::Ttl(cmd) => cmd.execute(backend),
+            Command::Pttl(cmd) => cmd.execute(backend),
+            Command::Persist(cmd) => cmd.execute(backend),
+        }
+    }
+}
+
+impl TryFrom<RespFrame> for Command {
+    type Error = CommandError;
+    fn try_from(value: RespFrame) -> Result<Self, Self::Error> {
+        match value {
+            RespFrame::Array(array) => Command::try_from(array),
+            _ => Err(CommandError::InvalidCommand(
+                "command must be an array".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Command {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        match value.first() {
+            Some(RespFrame::BulkString(cmd)) => {
+                match cmd.to_ascii_lowercase().as_slice() {
+                    b"echo" => Ok(Echo::try_from(value)?.into()),
+                    b"sadd" => Ok(SAdd::try_from(value)?.into()),
+                    b"smembers" => Ok(SMembers::try_from(value)?.into()),
+                    b"sismember" => Ok(SIsMember::try_from(value)?.into()),
+                    b"srem" => Ok(SRem::try_from(value)?.into()),
+                    b"scard" => Ok(SCard::try_from(value)?.into()),
+                    b"spop" => Ok(SPop::try_from(value)?.into()),
+                    b"sinter" => Ok(SInter::try_from(value)?.into()),
+                    b"sunion" => Ok(SUnion::try_from(value)?.into()),
+                    b"sdiff" => Ok(SDiff::try_from(value)?.into()),
+                    b"publish" => Ok(Publish::try_from(value)?.into()),
+                    b"subscribe" => Ok(Subscribe::try_from(value)?.into()),
+                    b"unsubscribe" => Ok(Unsubscribe::try_from(value)?.into()),
+                    b"save" => Ok(Save::try_from(value)?.into()),
+                    b"bgsave" => Ok(Bgsave::try_from(value)?.into()),
+                    b"expire" => Ok(Expire::try_from(value)?.into()),
+                    b"pexpire" => Ok(Pexpire::try_from(value)?.into()),
+                    b"ttl" => Ok(Ttl::try_from(value)?.into()),
+                    b"pttl" => Ok(Pttl::try_from(value)?.into()),
+                    b"persist" => Ok(Persist::try_from(value)?.into()),
+                    _ => Err(CommandError::InvalidCommand(
+                        "unknown command".to_string(),
+                    )),
+                }
+            }
+            _ => Err(CommandError::InvalidCommand(
+                "command must have a BulkString as the first argument".to_string(),
+            )),
+        }
+    }
+}
+
+impl From<Echo> for Command {
+    fn from(v: Echo) -> Self {
+        Command::Echo(v)
+    }
+}
+
+impl From<SAdd> for Command {
+    fn from(v: SAdd) -> Self {
+        Command::SAdd(v)
+    }
+}
+
+impl From<SMembers> for Command {
+    fn from(v: SMembers) -> Self {
+        Command::SMembers(v)
+    }
+}
+
+impl From<SIsMember> for Command {
+    fn from(v: SIsMember) -> Self {
+        Command::SIsMember(v)
+    }
+}
+
+impl From<SRem> for Command {
+    fn from(v: SRem) -> Self {
+        Command::SRem(v)
+    }
+}
+
+impl From<SCard> for Command {
+    fn from(v: SCard) -> Self {
+        Command::SCard(v)
+    }
+}
+
+impl From<SPop> for Command {
+    fn from(v: SPop) -> Self {
+        Command::SPop(v)
+    }
+}
+
+impl From<SInter> for Command {
+    fn from(v: SInter) -> Self {
+        Command::SInter(v)
+    }
+}
+
+impl From<SUnion> for Command {
+    fn from(v: SUnion) -> Self {
+        Command::SUnion(v)
+    }
+}
+
+impl From<SDiff> for Command {
+    fn from(v: SDiff) -> Self {
+        Command::SDiff(v)
+    }
+}
+
+impl From<Publish> for Command {
+    fn from(v: Publish) -> Self {
+        Command::Publish(v)
+    }
+}
+
+impl From<Subscribe> for Command {
+    fn from(v: Subscribe) -> Self {
+        Command::Subscribe(v)
+    }
+}
+
+impl From<Unsubscribe> for Command {
+    fn from(v: Unsubscribe) -> Self {
+        Command::Unsubscribe(v)
+    }
+}
+
+impl From<Save> for Command {
+    fn from(v: Save) -> Self {
+        Command::Save(v)
+    }
+}
+
+impl From<Bgsave> for Command {
+    fn from(v: Bgsave) -> Self {
+        Command::Bgsave(v)
+    }
+}
+
+impl From<Expire> for Command {
+    fn from(v: Expire) -> Self {
+        Command::Expire(v)
+    }
+}
+
+impl From<Pexpire> for Command {
+    fn from(v: Pexpire) -> Self {
+        Command::Pexpire(v)
+    }
+}
+
+impl From<Ttl> for Command {
+    fn from(v: Ttl) -> Self {
+        Command::Ttl(v)
+    }
+}
+
+impl From<Pttl> for Command {
+    fn from(v: Pttl) -> Self {
+        Command::Pttl(v)
+    }
+}
+
+impl From<Persist> for Command {
+    fn from(v: Persist) -> Self {
+        Command::Persist(v)
+    }
+}
+
+/// Check that the first `names.len()` elements of `value` are the expected
+/// command/subcommand BulkStrings and that exactly `n_args` arguments follow.
+pub(crate) fn validate_command(
+    value: &RespArray,
+    names: &[&'static str],
+    n_args: usize,
+) -> Result<(), CommandError> {
+    if value.len() != names.len() + n_args {
+        return Err(CommandError::InvalidArgument(format!(
+            "{} command requires {} argument(s), got {}",
+            names.join(" "),
+            n_args,
+            value.len() - names.len()
+        )));
+    }
+
+    for (i, name) in names.iter().enumerate() {
+        match value[i] {
+            RespFrame::BulkString(ref cmd) => {
+                if cmd.to_ascii_lowercase() != name.as_bytes() {
+                    return Err(CommandError::InvalidCommand(format!(
+                        "expected {}, got {}",
+                        name,
+                        String::from_utf8_lossy(cmd)
+                    )));
+                }
+            }
+            _ => {
+                return Err(CommandError::InvalidCommand(
+                    "command must have a BulkString as the first argument".to_string(),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn extract_args(value: RespArray, start: usize) -> Result<Vec<RespFrame>, CommandError> {
+    Ok(value.into_iter().skip(start).collect::<Vec<RespFrame>>())
+}
@@ -0,0 +1,100 @@
+use tracing::warn;
+
+use super::{validate_command, CommandError, CommandExecutor, Save};
+use crate::{Backend, BulkString, RespArray, RespFrame};
+
+#[derive(Debug)]
+pub struct Bgsave;
+
+impl CommandExecutor for Save {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        match backend.save() {
+            Ok(()) => RespFrame::BulkString(BulkString::from("OK")),
+            Err(e) => RespFrame::BulkString(BulkString::from(format!("ERR {}", e))),
+        }
+    }
+}
+
+impl CommandExecutor for Bgsave {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        let backend = backend.clone();
+        tokio::spawn(async move {
+            if let Err(e) = backend.save() {
+                warn!("background save failed: {}", e);
+            }
+        });
+        RespFrame::BulkString(BulkString::from("Background saving started"))
+    }
+}
+
+impl TryFrom<RespArray> for Save {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["save"], 0)?;
+        Ok(Save)
+    }
+}
+
+impl TryFrom<RespArray> for Bgsave {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["bgsave"], 0)?;
+        Ok(Bgsave)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RespDecode;
+    use anyhow::Result;
+    use bytes::BytesMut;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn tmp_path(name: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("simple-redis-cmd-{}-{}.rdb", name, nonce))
+    }
+
+    #[test]
+    fn test_save_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*1\r\n$4\r\nsave\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let _: Save = frame.try_into()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_bgsave_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*1\r\n$6\r\nbgsave\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let _: Bgsave = frame.try_into()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_command_round_trips_through_the_backends_path() -> Result<()> {
+        let path = tmp_path("save-command");
+        let backend = Backend::load_from(&path);
+        backend.set("key".to_string(), RespFrame::BulkString("value".into()));
+
+        let result = Save.execute(&backend);
+        assert_eq!(result, RespFrame::BulkString(BulkString::from("OK")));
+
+        let loaded = Backend::load_from(&path);
+        assert_eq!(
+            loaded.get("key"),
+            Some(RespFrame::BulkString("value".into()))
+        );
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
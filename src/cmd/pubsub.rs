@@ -0,0 +1,122 @@
+use super::{extract_args, validate_command, CommandError, CommandExecutor, Publish, Subscribe, Unsubscribe};
+use crate::{Backend, RespArray, RespFrame};
+
+impl CommandExecutor for Publish {
+    fn execute(self, backend: &Backend) -> RespFrame {
+        RespFrame::Integer(backend.publish(&self.channel, RespFrame::BulkString(self.message.into())))
+    }
+}
+
+// SUBSCRIBE/UNSUBSCRIBE need a per-connection sender/receiver pair, which
+// `CommandExecutor::execute` has no access to; the network loop intercepts
+// these two commands before dispatch and drives the subscription directly
+// (see `network::stream_handler`). These impls only run if one slips
+// through some other path.
+impl CommandExecutor for Subscribe {
+    fn execute(self, _backend: &Backend) -> RespFrame {
+        RespFrame::Error("ERR SUBSCRIBE is only valid within a connection's command loop".into())
+    }
+}
+
+impl CommandExecutor for Unsubscribe {
+    fn execute(self, _backend: &Backend) -> RespFrame {
+        RespFrame::Error("ERR UNSUBSCRIBE is only valid within a connection's command loop".into())
+    }
+}
+
+impl TryFrom<RespArray> for Publish {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["publish"], 2)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match (args.next(), args.next()) {
+            (Some(RespFrame::BulkString(channel)), Some(RespFrame::BulkString(message))) => {
+                Ok(Publish {
+                    channel: String::from_utf8(channel.0)?,
+                    message: String::from_utf8(message.0)?,
+                })
+            }
+            _ => Err(CommandError::InvalidArgument(
+                "Invalid channel or message".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for Subscribe {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        if value.len() < 2 {
+            return Err(CommandError::InvalidArgument(
+                "subscribe command requires at least 1 channel".to_string(),
+            ));
+        }
+
+        Ok(Subscribe {
+            channels: extract_channels(value)?,
+        })
+    }
+}
+
+impl TryFrom<RespArray> for Unsubscribe {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        Ok(Unsubscribe {
+            channels: extract_channels(value)?,
+        })
+    }
+}
+
+fn extract_channels(value: RespArray) -> Result<Vec<String>, CommandError> {
+    extract_args(value, 1)?
+        .into_iter()
+        .map(|f| match f {
+            RespFrame::BulkString(f) => Ok(String::from_utf8(f.0)?),
+            _ => Err(CommandError::InvalidArgument("Invalid channel".to_string())),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Backend, RespDecode};
+    use anyhow::Result;
+    use bytes::BytesMut;
+
+    #[test]
+    fn test_publish_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$7\r\npublish\r\n$4\r\nnews\r\n$5\r\nhello\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: Publish = frame.try_into()?;
+        assert_eq!(result.channel, "news");
+        assert_eq!(result.message, "hello");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subscribe_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n$1\r\nb\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: Subscribe = frame.try_into()?;
+        assert_eq!(result.channels, vec!["a".to_string(), "b".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_publish_with_no_subscribers_returns_zero() {
+        let backend = Backend::default();
+        let cmd = Publish {
+            channel: "news".to_string(),
+            message: "hello".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(0));
+    }
+}
@@ -1,10 +1,13 @@
-use super::{extract_args, validate_command, CommandExecutor, SAdd, SIsMember, SMembers};
+use super::{
+    extract_args, validate_command, CommandExecutor, SAdd, SCard, SDiff, SInter, SIsMember,
+    SMembers, SPop, SRem, SUnion,
+};
 use crate::{cmd::CommandError, RespArray, RespFrame};
 
 impl CommandExecutor for SAdd {
     fn execute(self, backend: &crate::Backend) -> RespFrame {
         let ret = backend.sadd(&self.key, &self.values);
-        RespFrame::BulkString(format!("(integer) {}", ret).into())
+        RespFrame::Integer(ret)
     }
 }
 
@@ -35,10 +38,70 @@ impl CommandExecutor for SMembers {
 impl CommandExecutor for SIsMember {
     fn execute(self, backend: &crate::Backend) -> RespFrame {
         let ret = backend.sismember(&self.key, self.value);
-        RespFrame::BulkString(format!("(integer) {}", ret).into())
+        RespFrame::Integer(ret)
     }
 }
 
+impl CommandExecutor for SRem {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        RespFrame::Integer(backend.srem(&self.key, &self.values))
+    }
+}
+
+impl CommandExecutor for SCard {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        RespFrame::Integer(backend.scard(&self.key))
+    }
+}
+
+impl CommandExecutor for SPop {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        let count = self.count.unwrap_or(1);
+        let popped = backend.spop(&self.key, count);
+
+        match self.count {
+            Some(_) => RespArray::new(
+                popped
+                    .into_iter()
+                    .map(|m| RespFrame::BulkString(m.into()))
+                    .collect::<Vec<RespFrame>>(),
+            )
+            .into(),
+            None => match popped.into_iter().next() {
+                Some(member) => RespFrame::BulkString(member.into()),
+                None => RespFrame::Null(crate::RespNull),
+            },
+        }
+    }
+}
+
+impl CommandExecutor for SInter {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        set_to_array(backend.sinter(&self.keys))
+    }
+}
+
+impl CommandExecutor for SUnion {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        set_to_array(backend.sunion(&self.keys))
+    }
+}
+
+impl CommandExecutor for SDiff {
+    fn execute(self, backend: &crate::Backend) -> RespFrame {
+        set_to_array(backend.sdiff(&self.keys))
+    }
+}
+
+fn set_to_array(set: std::collections::HashSet<String>) -> RespFrame {
+    RespArray::new(
+        set.into_iter()
+            .map(|m| RespFrame::BulkString(m.into()))
+            .collect::<Vec<RespFrame>>(),
+    )
+    .into()
+}
+
 impl TryFrom<RespArray> for SAdd {
     type Error = CommandError;
     fn try_from(value: RespArray) -> Result<Self, Self::Error> {
@@ -104,6 +167,124 @@ impl TryFrom<RespArray> for SIsMember {
     }
 }
 
+impl TryFrom<RespArray> for SRem {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        if value.len() < 3 {
+            return Err(CommandError::InvalidArgument(
+                "srem command requires at least 2 parameters".to_string(),
+            ));
+        }
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match args.next() {
+            Some(RespFrame::BulkString(key)) => {
+                let values = args
+                    .map(|f| match f {
+                        RespFrame::BulkString(f) => Ok(String::from_utf8(f.0)?),
+                        _ => Err(CommandError::InvalidArgument("Invalid member".to_string())),
+                    })
+                    .collect::<Result<Vec<String>, CommandError>>()?;
+                Ok(SRem {
+                    key: String::from_utf8(key.0)?,
+                    values,
+                })
+            }
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for SCard {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        validate_command(&value, &["scard"], 1)?;
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match args.next() {
+            Some(RespFrame::BulkString(key)) => Ok(SCard {
+                key: String::from_utf8(key.0)?,
+            }),
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for SPop {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        if value.len() < 2 || value.len() > 3 {
+            return Err(CommandError::InvalidArgument(
+                "spop command requires 1 or 2 parameters".to_string(),
+            ));
+        }
+
+        let mut args = extract_args(value, 1)?.into_iter();
+        match (args.next(), args.next()) {
+            (Some(RespFrame::BulkString(key)), None) => Ok(SPop {
+                key: String::from_utf8(key.0)?,
+                count: None,
+            }),
+            (Some(RespFrame::BulkString(key)), Some(RespFrame::BulkString(count))) => {
+                let count = String::from_utf8(count.0)?
+                    .parse::<usize>()
+                    .map_err(|_| CommandError::InvalidArgument("Invalid count".to_string()))?;
+                Ok(SPop {
+                    key: String::from_utf8(key.0)?,
+                    count: Some(count),
+                })
+            }
+            _ => Err(CommandError::InvalidArgument(
+                "Invalid key or count".to_string(),
+            )),
+        }
+    }
+}
+
+impl TryFrom<RespArray> for SInter {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        Ok(SInter {
+            keys: extract_set_keys(value, "sinter")?,
+        })
+    }
+}
+
+impl TryFrom<RespArray> for SUnion {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        Ok(SUnion {
+            keys: extract_set_keys(value, "sunion")?,
+        })
+    }
+}
+
+impl TryFrom<RespArray> for SDiff {
+    type Error = CommandError;
+    fn try_from(value: RespArray) -> Result<Self, Self::Error> {
+        Ok(SDiff {
+            keys: extract_set_keys(value, "sdiff")?,
+        })
+    }
+}
+
+fn extract_set_keys(value: RespArray, name: &str) -> Result<Vec<String>, CommandError> {
+    if value.len() < 2 {
+        return Err(CommandError::InvalidArgument(format!(
+            "{} command requires at least 1 parameter",
+            name
+        )));
+    }
+
+    extract_args(value, 1)?
+        .into_iter()
+        .map(|f| match f {
+            RespFrame::BulkString(f) => Ok(String::from_utf8(f.0)?),
+            _ => Err(CommandError::InvalidArgument("Invalid key".to_string())),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,20 +332,20 @@ mod tests {
 
     #[test]
     fn test_sadd_sismember_smembers_command() -> Result<()> {
-        let backend = Backend::new();
+        let backend = Backend::default();
         let cmd = SAdd {
             key: "set".to_string(),
             values: vec!["hello".to_string(), "world".to_string()],
         };
         let result = cmd.execute(&backend);
-        assert_eq!(result, RespFrame::BulkString("(integer) 2".into()));
+        assert_eq!(result, RespFrame::Integer(2));
 
         let cmd = SIsMember {
             key: "set".to_string(),
             value: "hello".to_string(),
         };
         let result = cmd.execute(&backend);
-        assert_eq!(result, RespFrame::BulkString("(integer) 1".into()));
+        assert_eq!(result, RespFrame::Integer(1));
 
         let cmd = SMembers {
             key: "set".to_string(),
@@ -186,14 +367,124 @@ mod tests {
             value: "not_member".to_string(),
         };
         let result = cmd.execute(&backend);
-        assert_eq!(result, RespFrame::BulkString("(integer) 0".into()));
+        assert_eq!(result, RespFrame::Integer(0));
 
         let cmd = SIsMember {
             key: "key_not_exist".to_string(),
             value: "whatever".to_string(),
         };
         let result = cmd.execute(&backend);
-        assert_eq!(result, RespFrame::BulkString("(integer) 0".into()));
+        assert_eq!(result, RespFrame::Integer(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_srem_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$4\r\nsrem\r\n$3\r\nset\r\n$5\r\nhello\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: SRem = frame.try_into()?;
+        assert_eq!(result.key, "set");
+        assert_eq!(result.values, vec!["hello".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_spop_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*2\r\n$4\r\nspop\r\n$3\r\nset\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: SPop = frame.try_into()?;
+        assert_eq!(result.key, "set");
+        assert_eq!(result.count, None);
+
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$4\r\nspop\r\n$3\r\nset\r\n$1\r\n2\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: SPop = frame.try_into()?;
+        assert_eq!(result.count, Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sinter_from_resp_array() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b"*3\r\n$6\r\nsinter\r\n$1\r\na\r\n$1\r\nb\r\n");
+        let frame = RespArray::decode(&mut buf)?;
+
+        let result: SInter = frame.try_into()?;
+        assert_eq!(result.keys, vec!["a".to_string(), "b".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_srem_scard_command() -> Result<()> {
+        let backend = Backend::default();
+        backend.sadd("set", &["a".to_string(), "b".to_string(), "c".to_string()]);
+
+        let cmd = SCard {
+            key: "set".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(3));
+
+        let cmd = SRem {
+            key: "set".to_string(),
+            values: vec!["a".to_string(), "not_member".to_string()],
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(1));
+
+        let cmd = SCard {
+            key: "set".to_string(),
+        };
+        assert_eq!(cmd.execute(&backend), RespFrame::Integer(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sinter_sunion_sdiff_command() -> Result<()> {
+        let backend = Backend::default();
+        backend.sadd("a", &["x".to_string(), "y".to_string()]);
+        backend.sadd("b", &["y".to_string(), "z".to_string()]);
+
+        let cmd = SInter {
+            keys: vec!["a".to_string(), "b".to_string()],
+        };
+        match cmd.execute(&backend) {
+            RespFrame::Array(arr) => assert_eq!(arr.len(), 1),
+            other => panic!("unexpected frame: {:?}", other),
+        }
+
+        let cmd = SUnion {
+            keys: vec!["a".to_string(), "b".to_string()],
+        };
+        match cmd.execute(&backend) {
+            RespFrame::Array(arr) => assert_eq!(arr.len(), 3),
+            other => panic!("unexpected frame: {:?}", other),
+        }
+
+        let cmd = SDiff {
+            keys: vec!["a".to_string(), "b".to_string()],
+        };
+        match cmd.execute(&backend) {
+            RespFrame::Array(arr) => assert_eq!(arr.len(), 1),
+            other => panic!("unexpected frame: {:?}", other),
+        }
+
+        let cmd = SInter {
+            keys: vec!["a".to_string(), "missing".to_string()],
+        };
+        match cmd.execute(&backend) {
+            RespFrame::Array(arr) => assert_eq!(arr.len(), 0),
+            other => panic!("unexpected frame: {:?}", other),
+        }
 
         Ok(())
     }
@@ -0,0 +1,9 @@
+mod backend;
+mod cmd;
+mod network;
+mod resp;
+
+pub use backend::*;
+pub use cmd::*;
+pub use network::*;
+pub use resp::*;
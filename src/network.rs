@@ -0,0 +1,128 @@
+use anyhow::Result;
+use bytes::BytesMut;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tracing::info;
+
+use crate::{
+    Backend, Command, CommandExecutor, RespArray, RespDecode, RespEncode, RespError, RespFrame,
+    Subscribe, Unsubscribe, PUBSUB_CHANNEL_CAPACITY,
+};
+
+/// Drive a single client connection: decode RESP frames off the socket,
+/// dispatch them against `backend`, and write the encoded reply back.
+///
+/// `SUBSCRIBE`/`UNSUBSCRIBE` are intercepted before dispatch, since they put
+/// the connection into a streaming state: once subscribed, pushed messages
+/// arriving on `sub_rx` must be forwarded to the socket alongside replies to
+/// any further (un)subscribe commands the client sends.
+pub async fn stream_handler(mut stream: TcpStream, backend: Backend) -> Result<()> {
+    let mut buf = BytesMut::with_capacity(4096);
+    let (sub_tx, mut sub_rx) = mpsc::channel::<RespFrame>(PUBSUB_CHANNEL_CAPACITY);
+    let mut subscribed: Vec<String> = Vec::new();
+
+    loop {
+        tokio::select! {
+            published = sub_rx.recv() => {
+                match published {
+                    Some(frame) => stream.write_all(&frame.encode()).await?,
+                    None => continue,
+                }
+            }
+            read = stream.read_buf(&mut buf) => {
+                let n = read?;
+                if n == 0 {
+                    info!("connection closed");
+                    for channel in &subscribed {
+                        backend.unsubscribe(channel, &sub_tx);
+                    }
+                    return Ok(());
+                }
+
+                while let Some(frame) = next_frame(&mut buf)? {
+                    match Command::try_from(frame) {
+                        Ok(Command::Subscribe(cmd)) => {
+                            handle_subscribe(&backend, &mut stream, &sub_tx, &mut subscribed, cmd).await?;
+                        }
+                        Ok(Command::Unsubscribe(cmd)) => {
+                            handle_unsubscribe(&backend, &mut stream, &sub_tx, &mut subscribed, cmd).await?;
+                        }
+                        Ok(cmd) => {
+                            let response = cmd.execute(&backend);
+                            stream.write_all(&response.encode()).await?;
+                        }
+                        Err(e) => {
+                            let response = RespFrame::Error(format!("ERR {}", e).into());
+                            stream.write_all(&response.encode()).await?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn handle_subscribe(
+    backend: &Backend,
+    stream: &mut TcpStream,
+    sub_tx: &mpsc::Sender<RespFrame>,
+    subscribed: &mut Vec<String>,
+    cmd: Subscribe,
+) -> Result<()> {
+    for channel in cmd.channels {
+        if !subscribed.contains(&channel) {
+            backend.subscribe(channel.clone(), sub_tx.clone());
+            subscribed.push(channel.clone());
+        }
+
+        // The count in the confirmation is this *connection's* running
+        // total of subscribed channels, not the channel's subscriber count.
+        let confirmation: RespFrame = RespArray::new(vec![
+            RespFrame::BulkString("subscribe".into()),
+            RespFrame::BulkString(channel.into()),
+            RespFrame::Integer(subscribed.len() as i64),
+        ])
+        .into();
+        stream.write_all(&confirmation.encode()).await?;
+    }
+    Ok(())
+}
+
+async fn handle_unsubscribe(
+    backend: &Backend,
+    stream: &mut TcpStream,
+    sub_tx: &mpsc::Sender<RespFrame>,
+    subscribed: &mut Vec<String>,
+    cmd: Unsubscribe,
+) -> Result<()> {
+    let channels = if cmd.channels.is_empty() {
+        subscribed.clone()
+    } else {
+        cmd.channels
+    };
+
+    for channel in channels {
+        backend.unsubscribe(&channel, sub_tx);
+        subscribed.retain(|c| c != &channel);
+
+        // Same convention as `handle_subscribe`: report this connection's
+        // remaining subscription count, not the channel's subscriber count.
+        let confirmation: RespFrame = RespArray::new(vec![
+            RespFrame::BulkString("unsubscribe".into()),
+            RespFrame::BulkString(channel.into()),
+            RespFrame::Integer(subscribed.len() as i64),
+        ])
+        .into();
+        stream.write_all(&confirmation.encode()).await?;
+    }
+    Ok(())
+}
+
+fn next_frame(buf: &mut BytesMut) -> Result<Option<RespFrame>, RespError> {
+    match RespFrame::decode(buf) {
+        Ok(frame) => Ok(Some(frame)),
+        Err(RespError::NotComplete) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,76 @@
+use bytes::{Buf, BytesMut};
+use std::ops::Deref;
+
+use super::{
+    decode::{parse_length, CRLF_LEN},
+    RespDecode, RespEncode, RespError, RespFrame,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RespArray(pub(crate) Vec<RespFrame>);
+
+impl RespArray {
+    pub fn new(s: impl Into<Vec<RespFrame>>) -> Self {
+        RespArray(s.into())
+    }
+}
+
+impl Deref for RespArray {
+    type Target = Vec<RespFrame>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl IntoIterator for RespArray {
+    type Item = RespFrame;
+    type IntoIter = std::vec::IntoIter<RespFrame>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// *<number-of-elements>\r\n<element-1>...<element-n>
+impl RespEncode for RespArray {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.0.len() * 16);
+        buf.extend_from_slice(format!("*{}\r\n", self.0.len()).as_bytes());
+        for frame in self.0.into_iter() {
+            buf.extend_from_slice(&frame.encode());
+        }
+        buf
+    }
+}
+
+impl RespDecode for RespArray {
+    const PREFIX: &'static str = "*";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let total_len = Self::expect_length(buf)?;
+        if buf.len() < total_len {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+
+        let mut frames = Vec::with_capacity(len);
+        for _ in 0..len {
+            frames.push(RespFrame::decode(buf)?);
+        }
+
+        Ok(RespArray::new(frames))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let mut total = end + CRLF_LEN;
+        let mut rest = &buf[total..];
+        for _ in 0..len {
+            let frame_len = RespFrame::expect_length(rest)?;
+            total += frame_len;
+            rest = &buf[total..];
+        }
+        Ok(total)
+    }
+}
@@ -0,0 +1,55 @@
+use bytes::{Buf, BytesMut};
+use std::ops::Deref;
+
+use super::{
+    decode::{parse_length, CRLF_LEN},
+    RespDecode, RespEncode, RespError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkString(pub Vec<u8>);
+
+impl Deref for BulkString {
+    type Target = Vec<u8>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Into<Vec<u8>>> From<T> for BulkString {
+    fn from(s: T) -> Self {
+        BulkString(s.into())
+    }
+}
+
+// $<length>\r\n<data>\r\n
+impl RespEncode for BulkString {
+    fn encode(self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.0.len() + 16);
+        buf.extend_from_slice(format!("${}\r\n", self.0.len()).as_bytes());
+        buf.extend_from_slice(&self.0);
+        buf.extend_from_slice(b"\r\n");
+        buf
+    }
+}
+
+impl RespDecode for BulkString {
+    const PREFIX: &'static str = "$";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        let remained = &buf[end + CRLF_LEN..];
+        if remained.len() < len + CRLF_LEN {
+            return Err(RespError::NotComplete);
+        }
+
+        buf.advance(end + CRLF_LEN);
+        let data = buf.split_to(len + CRLF_LEN);
+        Ok(BulkString(data[..len].to_vec()))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let (end, len) = parse_length(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN + len + CRLF_LEN)
+    }
+}
@@ -0,0 +1,50 @@
+use bytes::BytesMut;
+
+use super::RespError;
+
+pub const CRLF_LEN: usize = 2;
+
+/// Decode a RESP frame from a `BytesMut` buffer.
+pub trait RespDecode: Sized {
+    const PREFIX: &'static str;
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError>;
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError>;
+}
+
+/// Find the index of the `nth` CRLF in `buf`, returning `None` if the buffer
+/// doesn't contain enough terminators yet (i.e. the frame isn't complete).
+pub(crate) fn find_crlf(buf: &[u8], nth: usize) -> Option<usize> {
+    let mut count = 0;
+    for i in 1..buf.len().saturating_sub(1) {
+        if buf[i] == b'\r' && buf[i + 1] == b'\n' {
+            count += 1;
+            if count == nth {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Validate that `buf` starts with `prefix` and locate the end of its first
+/// line (the index of the `\r`), erroring if the frame is incomplete.
+pub(crate) fn extract_simple_frame_data(buf: &[u8], prefix: &str) -> Result<usize, RespError> {
+    if buf.len() < 3 {
+        return Err(RespError::NotComplete);
+    }
+    if !buf.starts_with(prefix.as_bytes()) {
+        return Err(RespError::InvalidFrameType(format!(
+            "expect: {}, got: {:?}",
+            prefix, buf
+        )));
+    }
+    find_crlf(buf, 1).ok_or(RespError::NotComplete)
+}
+
+/// Parse the `$<len>\r\n` / `*<len>\r\n` style length header that precedes
+/// bulk strings and arrays.
+pub(crate) fn parse_length(buf: &[u8], prefix: &str) -> Result<(usize, usize), RespError> {
+    let end = extract_simple_frame_data(buf, prefix)?;
+    let s = String::from_utf8_lossy(&buf[prefix.len()..end]);
+    Ok((end, s.parse()?))
+}
@@ -0,0 +1,4 @@
+/// Encode a value into its RESP wire representation.
+pub trait RespEncode {
+    fn encode(self) -> Vec<u8>;
+}
@@ -0,0 +1,57 @@
+use bytes::BytesMut;
+
+use super::{decode::extract_simple_frame_data, decode::CRLF_LEN, RespDecode, RespEncode, RespError};
+
+// :[<+|->]<value>\r\n
+impl RespEncode for i64 {
+    fn encode(self) -> Vec<u8> {
+        format!(":{}\r\n", self).into_bytes()
+    }
+}
+
+impl RespDecode for i64 {
+    const PREFIX: &'static str = ":";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        let s = String::from_utf8_lossy(&data[Self::PREFIX.len()..end]);
+        Ok(s.parse()?)
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RespFrame;
+    use anyhow::Result;
+
+    #[test]
+    fn test_integer_encode() {
+        let frame: RespFrame = 123.into();
+        assert_eq!(frame.encode(), b":123\r\n");
+
+        let frame: RespFrame = (-123i64).into();
+        assert_eq!(frame.encode(), b":-123\r\n");
+    }
+
+    #[test]
+    fn test_integer_decode() -> Result<()> {
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b":123\r\n");
+        let frame = i64::decode(&mut buf)?;
+        assert_eq!(frame, 123);
+
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(b":-123\r\n");
+        let frame = i64::decode(&mut buf)?;
+        assert_eq!(frame, -123);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+mod array;
+mod bulk_string;
+mod decode;
+mod encode;
+mod error;
+mod integer;
+mod null;
+mod simple_string;
+
+use bytes::BytesMut;
+
+pub use array::RespArray;
+pub use bulk_string::BulkString;
+pub use decode::RespDecode;
+pub use encode::RespEncode;
+pub use error::RespError;
+pub use null::RespNull;
+pub use simple_string::{SimpleError, SimpleString};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RespFrame {
+    SimpleString(SimpleString),
+    Error(SimpleError),
+    Integer(i64),
+    BulkString(BulkString),
+    Array(RespArray),
+    Null(RespNull),
+}
+
+impl RespEncode for RespFrame {
+    fn encode(self) -> Vec<u8> {
+        match self {
+            RespFrame::SimpleString(s) => s.encode(),
+            RespFrame::Error(e) => e.encode(),
+            RespFrame::Integer(i) => i.encode(),
+            RespFrame::BulkString(s) => s.encode(),
+            RespFrame::Array(a) => a.encode(),
+            RespFrame::Null(n) => n.encode(),
+        }
+    }
+}
+
+impl RespDecode for RespFrame {
+    const PREFIX: &'static str = "";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        match buf.first() {
+            Some(b'+') => Ok(SimpleString::decode(buf)?.into()),
+            Some(b'-') => Ok(SimpleError::decode(buf)?.into()),
+            Some(b':') => Ok(i64::decode(buf)?.into()),
+            Some(b'$') => Ok(BulkString::decode(buf)?.into()),
+            Some(b'*') => Ok(RespArray::decode(buf)?.into()),
+            Some(b'_') => Ok(RespNull::decode(buf)?.into()),
+            Some(prefix) => Err(RespError::InvalidFrameType(format!(
+                "unknown frame prefix: {}",
+                *prefix as char
+            ))),
+            None => Err(RespError::NotComplete),
+        }
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        match buf.first() {
+            Some(b'+') => SimpleString::expect_length(buf),
+            Some(b'-') => SimpleError::expect_length(buf),
+            Some(b':') => i64::expect_length(buf),
+            Some(b'$') => BulkString::expect_length(buf),
+            Some(b'*') => RespArray::expect_length(buf),
+            Some(b'_') => RespNull::expect_length(buf),
+            Some(prefix) => Err(RespError::InvalidFrameType(format!(
+                "unknown frame prefix: {}",
+                *prefix as char
+            ))),
+            None => Err(RespError::NotComplete),
+        }
+    }
+}
+
+impl From<SimpleString> for RespFrame {
+    fn from(s: SimpleString) -> Self {
+        RespFrame::SimpleString(s)
+    }
+}
+
+impl From<SimpleError> for RespFrame {
+    fn from(s: SimpleError) -> Self {
+        RespFrame::Error(s)
+    }
+}
+
+impl From<i64> for RespFrame {
+    fn from(i: i64) -> Self {
+        RespFrame::Integer(i)
+    }
+}
+
+impl From<BulkString> for RespFrame {
+    fn from(s: BulkString) -> Self {
+        RespFrame::BulkString(s)
+    }
+}
+
+impl From<RespArray> for RespFrame {
+    fn from(a: RespArray) -> Self {
+        RespFrame::Array(a)
+    }
+}
+
+impl From<RespNull> for RespFrame {
+    fn from(n: RespNull) -> Self {
+        RespFrame::Null(n)
+    }
+}
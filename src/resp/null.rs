@@ -0,0 +1,29 @@
+use bytes::{Buf, BytesMut};
+
+use super::{RespDecode, RespEncode, RespError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RespNull;
+
+// _\r\n
+impl RespEncode for RespNull {
+    fn encode(self) -> Vec<u8> {
+        b"_\r\n".to_vec()
+    }
+}
+
+impl RespDecode for RespNull {
+    const PREFIX: &'static str = "_";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        if buf.len() < 3 || &buf[..3] != b"_\r\n" {
+            return Err(RespError::NotComplete);
+        }
+        buf.advance(3);
+        Ok(RespNull)
+    }
+
+    fn expect_length(_buf: &[u8]) -> Result<usize, RespError> {
+        Ok(3)
+    }
+}
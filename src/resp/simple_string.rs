@@ -0,0 +1,80 @@
+use bytes::BytesMut;
+use std::ops::Deref;
+
+use super::{decode::extract_simple_frame_data, decode::CRLF_LEN, RespDecode, RespEncode, RespError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimpleString(pub(crate) String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimpleError(pub(crate) String);
+
+impl Deref for SimpleString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Into<String>> From<T> for SimpleString {
+    fn from(s: T) -> Self {
+        SimpleString(s.into())
+    }
+}
+
+impl Deref for SimpleError {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Into<String>> From<T> for SimpleError {
+    fn from(s: T) -> Self {
+        SimpleError(s.into())
+    }
+}
+
+// +OK\r\n
+impl RespEncode for SimpleString {
+    fn encode(self) -> Vec<u8> {
+        format!("+{}\r\n", self.0).into_bytes()
+    }
+}
+
+// -ERR unknown command\r\n
+impl RespEncode for SimpleError {
+    fn encode(self) -> Vec<u8> {
+        format!("-{}\r\n", self.0).into_bytes()
+    }
+}
+
+impl RespDecode for SimpleString {
+    const PREFIX: &'static str = "+";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        Ok(SimpleString(String::from_utf8_lossy(&data[1..end]).to_string()))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}
+
+impl RespDecode for SimpleError {
+    const PREFIX: &'static str = "-";
+
+    fn decode(buf: &mut BytesMut) -> Result<Self, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        let data = buf.split_to(end + CRLF_LEN);
+        Ok(SimpleError(String::from_utf8_lossy(&data[1..end]).to_string()))
+    }
+
+    fn expect_length(buf: &[u8]) -> Result<usize, RespError> {
+        let end = extract_simple_frame_data(buf, Self::PREFIX)?;
+        Ok(end + CRLF_LEN)
+    }
+}